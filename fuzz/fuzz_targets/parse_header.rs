@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rheader::Header;
+
+// Feeds arbitrary byte buffers into `Header::parse` and asserts it never panics,
+// regardless of truncation, missing END cards, or malformed cards.
+fuzz_target!(|data: &[u8]| {
+    let _ = Header::parse(data);
+});
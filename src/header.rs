@@ -7,7 +7,7 @@
 
 use std::fmt::Display;
 use std::fs::File;
-use std::io::{BufReader, Read};
+use std::io::{BufRead, BufReader, Read, Write};
 use std::path::Path;
 use std::sync::LazyLock;
 
@@ -43,6 +43,28 @@ impl Header {
     pub fn num_keywords(&self) -> usize {
         self.keywords.len()
     }
+
+    /// Parses a `Header` from an in-memory buffer of header bytes, e.g. one already
+    /// read from S3 or HTTP and decompressed by the caller.
+    pub fn parse(bytes: &[u8]) -> anyhow::Result<Header> {
+        read_header_from_reader(bytes, GzipMode::Never)
+    }
+
+    /// Returns the value of the `EXTNAME` keyword, if present.
+    pub fn extname(&self) -> Option<String> {
+        match &self.get_keyword("EXTNAME")?.value {
+            FITSValue::String(s) => Some(s.clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns the integer value of a keyword, if present and integer-valued.
+    fn get_integer(&self, key: &str) -> Option<i64> {
+        match &self.get_keyword(key)?.value {
+            FITSValue::Integer(i) => Some(*i),
+            _ => None,
+        }
+    }
 }
 
 impl IntoIterator for Header {
@@ -92,6 +114,19 @@ impl Display for Keyword {
 }
 
 impl Keyword {
+    /// Creates a new keyword from a name, value, and optional comment.
+    pub fn new(name: String, value: FITSValue, comment: Option<String>) -> Self {
+        let raw_value = Bytes::copy_from_slice(value.to_string().as_bytes());
+
+        Keyword {
+            name,
+            value,
+            comment,
+            raw_value,
+            valid: true,
+        }
+    }
+
     /// Returns whether the keyword was parsed successfully.
     pub fn is_valid(&self) -> bool {
         self.valid
@@ -122,13 +157,24 @@ impl Display for FITSValue {
     }
 }
 
+/// Controls whether a FITS data source should be gzip-decompressed before parsing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GzipMode {
+    /// Detect gzip compression from the source's magic number.
+    Auto,
+    /// Always treat the source as gzip-compressed.
+    Always,
+    /// Never attempt gzip decompression.
+    Never,
+}
+
 /// Parses a FITS keyword value from a byte slice.
 pub fn parse_keyword_value(value: &[u8]) -> anyhow::Result<FITSValue> {
     let value_str = String::from_utf8_lossy(value).trim().to_string();
 
-    let value = if value_str.starts_with('\'') && value_str.ends_with('\'') {
-        let unquoted = value_str[1..value_str.len() - 1].trim_end().to_string();
-        FITSValue::String(unquoted)
+    let value = if value_str.starts_with('\'') && value_str.ends_with('\'') && value_str.len() >= 2
+    {
+        FITSValue::String(unquote(value_str.as_bytes()))
     } else if value_str.eq_ignore_ascii_case("T") {
         FITSValue::Bool(true)
     } else if value_str.eq_ignore_ascii_case("F") {
@@ -154,88 +200,913 @@ static KEYWORD_RE: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"([A-Z0-9_-]{1,8})\s*=\s*(?:('[^']*')|([^/\s]*))\s*(?:/\s*(.*))?").unwrap()
 });
 
-/// Reads a FITS header from the specified file path.
-pub fn read_header<T: AsRef<Path>>(path: T) -> anyhow::Result<Header> {
-    // Open the file in read-only mode with buffer.
-    let reader = BufReader::new(File::open(&path)?);
+// Regular expression for the ESO HIERARCH convention, e.g.
+// `HIERARCH ESO DET CHIP1 GAIN = 2.1 / comment`. The hierarchical name is everything
+// between `HIERARCH` and the value indicator, kept as a single space-separated string.
+static HIERARCH_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^HIERARCH\s+(.+?)\s*=\s*(?:('[^']*')|([^/\s]*))\s*(?:/\s*(.*))?").unwrap()
+});
+
+// Regular expression for a `CONTINUE` card used by the long-string convention, e.g.
+// `CONTINUE  'rest of the string&' / comment`. Unlike regular cards, CONTINUE cards
+// carry no `=` value indicator.
+static CONTINUE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^CONTINUE\s+('[^']*')\s*(?:/\s*(.*))?").unwrap());
 
-    // Create a decoder that handles gzip files if necessary.
-    let mut decoder: Box<dyn Read> = if crate::tools::is_gzip_file(&path).unwrap_or(false) {
-        Box::new(GzDecoder::new(reader))
+/// Builds a `Keyword` from a name and the raw (possibly quoted) value bytes captured
+/// from a card, parsing the value and tracking whether it was understood.
+fn build_keyword(name: String, raw_value: &[u8], comment: Option<String>) -> Keyword {
+    if let Ok(value) = parse_keyword_value(raw_value) {
+        Keyword {
+            name,
+            value,
+            comment,
+            raw_value: Bytes::copy_from_slice(raw_value),
+            valid: true,
+        }
+    } else {
+        Keyword {
+            name,
+            value: FITSValue::Invalid,
+            comment,
+            raw_value: Bytes::copy_from_slice(raw_value),
+            valid: false,
+        }
+    }
+}
+
+/// Extracts the raw value bytes (quoted or unquoted) and comment from a `KEYWORD_RE` or
+/// `HIERARCH_RE` capture, where both regexes place them at the same group indices.
+fn value_and_comment<'c>(caps: &'c regex::bytes::Captures) -> (&'c [u8], Option<String>) {
+    let raw_value = if let Some(val) = caps.get(2) {
+        val.as_bytes().trim_ascii_end()
+    } else if let Some(val) = caps.get(3) {
+        val.as_bytes().trim_ascii_end()
+    } else {
+        &[]
+    };
+
+    let comment_string = if let Some(com) = caps.get(4) {
+        String::from_utf8_lossy(com.as_bytes()).trim().to_string()
     } else {
-        Box::new(reader)
+        "".to_string()
     };
 
+    let comment = if comment_string.is_empty() {
+        None
+    } else {
+        Some(comment_string)
+    };
+
+    (raw_value, comment)
+}
+
+/// Unquotes a FITS string literal (e.g. `'foo bar'`), trimming trailing padding inside
+/// the quotes.
+fn unquote(raw: &[u8]) -> String {
+    let s = String::from_utf8_lossy(raw).trim().to_string();
+    if s.starts_with('\'') && s.ends_with('\'') && s.len() >= 2 {
+        s[1..s.len() - 1].trim_end().to_string()
+    } else {
+        s
+    }
+}
+
+// Regular expression that locates the `END` keyword card. Defined as a static for the
+// same reason as `KEYWORD_RE`.
+static END_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(END)\s*$").unwrap());
+
+/// Maximum number of 2880-byte blocks scanned for an `END` keyword before giving up.
+/// Used as the default by `read_header_from_reader`; guards against unbounded reads on
+/// truncated or corrupted input that never yields an `END` card.
+pub const DEFAULT_MAX_HEADER_BLOCKS: usize = 1000;
+
+/// Errors produced while scanning and parsing a FITS header.
+#[derive(Debug, thiserror::Error)]
+pub enum HeaderError {
+    /// Reached the end of the stream before an `END` keyword was found.
+    #[error("unexpected end of file before an END keyword was found")]
+    UnexpectedEof,
+
+    /// The stream ended partway through a 2880-byte block.
+    #[error("truncated header block: expected {expected} bytes, got {actual}")]
+    TruncatedBlock { expected: usize, actual: usize },
+
+    /// A header card could not be decoded.
+    #[error("malformed header card: {0}")]
+    MalformedCard(String),
+
+    /// The header scan exceeded `max_blocks` 2880-byte blocks without finding an `END`.
+    #[error("header exceeded the maximum of {0} 2880-byte blocks without an END keyword")]
+    TooManyBlocks(usize),
+
+    /// An I/O error occurred while reading the header.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// Reads as many bytes as available into `buf`, stopping early (without error) on a
+/// clean end of stream. Returns the number of bytes actually read.
+fn read_up_to<R: Read>(reader: &mut R, buf: &mut [u8]) -> std::io::Result<usize> {
+    let mut total = 0;
+
+    while total < buf.len() {
+        match reader.read(&mut buf[total..]) {
+            Ok(0) => break,
+            Ok(n) => total += n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(total)
+}
+
+/// Reads the raw header card bytes from `reader`, consuming 2880-byte blocks until the
+/// `END` keyword is found, without panicking on truncated or malformed input.
+fn read_header_blocks<R: Read>(reader: &mut R, max_blocks: usize) -> anyhow::Result<Vec<u8>> {
     let mut header_buf = Vec::new();
     let mut buf = [0u8; 2880];
-
-    let end_re = Regex::new(r"(END)\s*$").unwrap();
+    let mut blocks_read = 0;
 
     // Read the header in 2880-byte blocks until we find the END keyword.
     loop {
-        decoder
-            .read_exact(&mut buf)
-            .expect("Failed to read exact number of bytes");
+        let n = read_up_to(reader, &mut buf)?;
+
+        if n == 0 {
+            return Err(HeaderError::UnexpectedEof.into());
+        } else if n < buf.len() {
+            return Err(HeaderError::TruncatedBlock {
+                expected: buf.len(),
+                actual: n,
+            }
+            .into());
+        }
+
+        if let Some(m) = END_RE.find(&buf) {
+            let end_pos = m.start();
+
+            if end_pos % 80 != 0 {
+                return Err(HeaderError::MalformedCard(
+                    "END keyword is not aligned to an 80-byte card boundary".to_string(),
+                )
+                .into());
+            }
 
-        if end_re.is_match(&buf) {
-            let end_pos = end_re.find(&buf).unwrap().start();
             header_buf.extend_from_slice(&buf[..end_pos]);
             break;
         }
 
         header_buf.extend_from_slice(&buf);
+
+        blocks_read += 1;
+        if blocks_read > max_blocks {
+            return Err(HeaderError::TooManyBlocks(max_blocks).into());
+        }
     }
 
-    // Create a new Header and parse keywords.
+    Ok(header_buf)
+}
+
+/// Parses a sequence of 80-byte header cards into a `Header`, stitching together
+/// `CONTINUE`-style long strings and recognizing `HIERARCH` keywords along the way.
+fn parse_header_cards(header_buf: &[u8]) -> Header {
     let mut header = Header::new();
+    let cards: Vec<&[u8]> = header_buf.chunks(80).collect();
+    let mut i = 0;
 
-    for keyword_chunk in header_buf.chunks(80) {
-        if let Some(caps) = KEYWORD_RE.captures(keyword_chunk) {
-            let name = String::from_utf8_lossy(&caps[1]).trim().to_string();
-
-            let raw_value = if let Some(val) = caps.get(2) {
-                val.as_bytes().trim_ascii_end()
-            } else if let Some(val) = caps.get(3) {
-                val.as_bytes().trim_ascii_end()
-            } else {
-                &[]
-            };
-
-            let comment_string = if let Some(com) = caps.get(4) {
-                String::from_utf8_lossy(com.as_bytes()).trim().to_string()
-            } else {
-                "".to_string()
-            };
-
-            // Handle empty comments.
-            let comment = if comment_string.is_empty() {
-                None
-            } else {
-                Some(comment_string)
-            };
-
-            // Convert the raw value to a FITSValue.
-            let keyword = if let Ok(value) = parse_keyword_value(&raw_value) {
-                Keyword {
-                    name: name.clone(),
-                    value,
-                    comment,
-                    raw_value: Bytes::copy_from_slice(raw_value),
-                    valid: true,
-                }
-            } else {
-                Keyword {
-                    name: name.clone(),
-                    value: FITSValue::Invalid,
-                    comment,
-                    raw_value: Bytes::copy_from_slice(raw_value),
-                    valid: false,
+    while i < cards.len() {
+        let card = cards[i];
+
+        // Try the HIERARCH convention first since its name pattern is a superset of
+        // the plain `KEYWORD_RE` one; both place the value/comment at the same group
+        // indices, so the rest of the handling (including CONTINUE stitching) is shared.
+        let (name, caps) = if let Some(caps) = HIERARCH_RE.captures(card) {
+            (String::from_utf8_lossy(&caps[1]).trim().to_string(), caps)
+        } else if let Some(caps) = KEYWORD_RE.captures(card) {
+            (String::from_utf8_lossy(&caps[1]).trim().to_string(), caps)
+        } else {
+            i += 1;
+            continue;
+        };
+
+        let is_string = caps.get(2).is_some();
+        let (raw_value, mut comment) = value_and_comment(&caps);
+
+        if is_string {
+            let mut value = unquote(raw_value);
+
+            // Stitch subsequent CONTINUE cards onto a long-string value.
+            while value.ends_with('&') && i + 1 < cards.len() {
+                match CONTINUE_RE.captures(cards[i + 1]) {
+                    Some(continue_caps) => {
+                        value.pop();
+                        value.push_str(&unquote(&continue_caps[1]));
+
+                        if let Some(com) = continue_caps.get(2) {
+                            let com_str =
+                                String::from_utf8_lossy(com.as_bytes()).trim().to_string();
+                            if !com_str.is_empty() {
+                                comment = Some(com_str);
+                            }
+                        }
+
+                        i += 1;
+                    }
+                    None => break,
                 }
-            };
+            }
 
-            header.add_keyword(keyword);
+            // Re-quote so this goes through the same value parsing as a
+            // single-card string.
+            let quoted = format!("'{}'", value);
+            header.add_keyword(build_keyword(name, quoted.as_bytes(), comment));
+        } else {
+            header.add_keyword(build_keyword(name, raw_value, comment));
         }
+
+        i += 1;
     }
 
-    Ok(header)
+    header
+}
+
+/// Reads a FITS header from any `Read` source, honoring `gzip` to decide whether the
+/// stream should be decompressed before parsing. Use this to parse a header out of an
+/// in-memory buffer or a network stream without going through the filesystem.
+///
+/// The scan is capped at `DEFAULT_MAX_HEADER_BLOCKS` 2880-byte blocks; use
+/// `read_header_from_reader_with_limit` to customize that cap.
+pub fn read_header_from_reader<R: Read>(reader: R, gzip: GzipMode) -> anyhow::Result<Header> {
+    read_header_from_reader_with_limit(reader, gzip, DEFAULT_MAX_HEADER_BLOCKS)
+}
+
+/// Like `read_header_from_reader`, but with a configurable cap on the number of
+/// 2880-byte blocks scanned for an `END` keyword.
+pub fn read_header_from_reader_with_limit<R: Read>(
+    reader: R,
+    gzip: GzipMode,
+    max_blocks: usize,
+) -> anyhow::Result<Header> {
+    let mut buf_reader = BufReader::new(reader);
+
+    let is_gzip = match gzip {
+        GzipMode::Always => true,
+        GzipMode::Never => false,
+        GzipMode::Auto => {
+            let peek = buf_reader.fill_buf()?;
+            peek.len() >= 2 && peek[0] == 0x1F && peek[1] == 0x8B
+        }
+    };
+
+    let mut decoder: Box<dyn Read> = if is_gzip {
+        Box::new(GzDecoder::new(buf_reader))
+    } else {
+        Box::new(buf_reader)
+    };
+
+    let header_buf = read_header_blocks(&mut decoder, max_blocks)?;
+
+    Ok(parse_header_cards(&header_buf))
+}
+
+/// Reads a FITS header from the specified file path.
+pub fn read_header<T: AsRef<Path>>(path: T) -> anyhow::Result<Header> {
+    let file = File::open(&path)?;
+
+    // The path is a seekable source, so we can rely on the precise magic-number check
+    // instead of the generic `GzipMode::Auto` peek.
+    let gzip = if crate::tools::is_gzip_file(&path).unwrap_or(false) {
+        GzipMode::Always
+    } else {
+        GzipMode::Never
+    };
+
+    read_header_from_reader(file, gzip)
+}
+
+/// Largest `NAXIS` the FITS standard allows; also caps the per-axis loop below so a
+/// header with an absurd `NAXIS` can't be used to stall a scan.
+const MAX_NAXIS: i64 = 999;
+
+/// Computes the size, in bytes and rounded up to the next 2880-byte block, of the data
+/// unit that follows `header` according to `NAXIS`/`NAXISn`, `BITPIX`, and the binary
+/// table `PCOUNT`/`GCOUNT` keywords.
+fn data_unit_size(header: &Header) -> u64 {
+    let naxis = header.get_integer("NAXIS").unwrap_or(0).clamp(0, MAX_NAXIS) as usize;
+
+    if naxis == 0 {
+        return 0;
+    }
+
+    let bitpix = header.get_integer("BITPIX").unwrap_or(8).unsigned_abs();
+    let pcount = header.get_integer("PCOUNT").unwrap_or(0).max(0) as u64;
+    let gcount = header.get_integer("GCOUNT").unwrap_or(1).max(1) as u64;
+
+    let axes_product: u64 = (1..=naxis)
+        .map(|i| header.get_integer(&format!("NAXIS{}", i)).unwrap_or(0).max(0) as u64)
+        .fold(1u64, |acc, axis| acc.saturating_mul(axis));
+
+    let data_bytes = (bitpix / 8)
+        .saturating_mul(gcount)
+        .saturating_mul(pcount.saturating_add(axes_product));
+
+    data_bytes.div_ceil(2880).saturating_mul(2880)
+}
+
+/// Reads every HDU in a (possibly multi-extension) FITS file, skipping each data unit
+/// based on its header keywords to find the start of the next one.
+pub fn read_hdus<T: AsRef<Path>>(path: T) -> anyhow::Result<Vec<Header>> {
+    let file = File::open(&path)?;
+
+    // The path is a seekable source, so we can rely on the precise magic-number check
+    // instead of the generic `GzipMode::Auto` peek.
+    let gzip = if crate::tools::is_gzip_file(&path).unwrap_or(false) {
+        GzipMode::Always
+    } else {
+        GzipMode::Never
+    };
+
+    let buf_reader = BufReader::new(file);
+    let mut reader: Box<dyn BufRead> = match gzip {
+        GzipMode::Always => Box::new(BufReader::new(GzDecoder::new(buf_reader))),
+        _ => Box::new(buf_reader),
+    };
+
+    let mut headers = Vec::new();
+
+    loop {
+        // A clean EOF here means there are no more HDUs left to read.
+        if reader.fill_buf()?.is_empty() {
+            break;
+        }
+
+        let header_buf = read_header_blocks(&mut reader, DEFAULT_MAX_HEADER_BLOCKS)?;
+        let header = parse_header_cards(&header_buf);
+
+        let data_size = data_unit_size(&header);
+        if data_size > 0 {
+            std::io::copy(&mut reader.by_ref().take(data_size), &mut std::io::sink())?;
+        }
+
+        headers.push(header);
+    }
+
+    Ok(headers)
+}
+
+/// Formats a FITS keyword value for the fixed-width value field of a card: strings are
+/// quoted and padded to at least 8 characters, other values are left as their display
+/// form (to be right-justified by the caller).
+fn format_card_value(value: &FITSValue) -> String {
+    match value {
+        FITSValue::Null | FITSValue::Invalid => "".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Left-justifies `s` in a field of `width` bytes, padding with spaces. Unlike the
+/// `{:<width}` format specifier (which counts Unicode scalar values), this counts UTF-8
+/// bytes, so the result is exactly `width` bytes wide even when `s` isn't plain ASCII.
+/// A card is a fixed number of *bytes*, not characters, and FITS header content is
+/// nominally ASCII-only, but nothing stops a caller from handing us a non-ASCII string.
+fn pad_right_bytes(s: &str, width: usize) -> String {
+    if s.len() >= width {
+        s.to_string()
+    } else {
+        let mut out = String::with_capacity(width);
+        out.push_str(s);
+        out.extend(std::iter::repeat(' ').take(width - s.len()));
+        out
+    }
+}
+
+/// Right-justifies `s` in a field of `width` bytes, padding with spaces. See
+/// `pad_right_bytes` for why this counts bytes rather than characters.
+fn pad_left_bytes(s: &str, width: usize) -> String {
+    if s.len() >= width {
+        s.to_string()
+    } else {
+        let mut out = String::with_capacity(width);
+        out.extend(std::iter::repeat(' ').take(width - s.len()));
+        out.push_str(s);
+        out
+    }
+}
+
+/// Truncates `s` to at most `max_bytes` bytes, in place, rounding down to the nearest
+/// character boundary rather than splitting a multi-byte character (which would both
+/// panic and corrupt the card).
+fn truncate_to_bytes(s: &mut String, max_bytes: usize) {
+    if s.len() > max_bytes {
+        let mut end = max_bytes;
+        while end > 0 && !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        s.truncate(end);
+    }
+}
+
+/// Appends `comment`, truncated to whatever room is left in an 80-byte card, after an
+/// already-built `card` prefix. Truncating a comment for lack of space is normal FITS
+/// writer behavior; the value itself must never be the thing that gets cut.
+fn push_comment(card: &mut String, comment: &str) {
+    let remaining = 80usize.saturating_sub(card.len() + " / ".len());
+    if remaining > 0 {
+        card.push_str(" / ");
+
+        let mut used = 0;
+        for ch in comment.chars() {
+            if used + ch.len_utf8() > remaining {
+                break;
+            }
+            card.push(ch);
+            used += ch.len_utf8();
+        }
+    }
+}
+
+/// Minimum number of bytes reserved on the final chunk for a trailing ` / comment`, so
+/// that a long value never eats the whole 80-byte budget and leaves `push_comment` with
+/// nothing to work with.
+const COMMENT_RESERVE: usize = 20;
+
+/// Largest number of bytes of string content that fit inside a single card's quoted
+/// value field, given the width of `name_field` (8 columns for a plain keyword, or
+/// longer for a `HIERARCH` name). Every chunk uses the same size, sized off whichever is
+/// wider: the keyword's own `NAME= ` field or the fixed `CONTINUE  ` field used by every
+/// card after the first. Leaves 2 bytes for the surrounding quotes and 1 byte of
+/// headroom for the trailing `&` continuation marker on every chunk but the last.
+fn max_chunk_content(name_field_len: usize) -> usize {
+    let first_prefix_len = name_field_len + "= ".len();
+    let continue_prefix_len = "CONTINUE".len() + 2;
+    let prefix_len = first_prefix_len.max(continue_prefix_len);
+    80usize.saturating_sub(prefix_len + 2 + 1).max(1)
+}
+
+/// Splits a string value into chunks that each fit inside a single quoted FITS value
+/// field, per the `CONTINUE` long-string convention. Chunk boundaries always land on a
+/// UTF-8 character boundary, even if that leaves a chunk a byte or two short of the
+/// budget. When `has_comment` is set, the final chunk is kept short enough to leave
+/// `COMMENT_RESERVE` bytes free for the comment that `format_string_card` will append to
+/// the last card.
+fn split_string_value(value: &str, name_field_len: usize, has_comment: bool) -> Vec<String> {
+    let max_content = max_chunk_content(name_field_len);
+    let last_max = if has_comment {
+        max_content.saturating_sub(COMMENT_RESERVE).max(1)
+    } else {
+        max_content
+    };
+
+    if value.len() <= last_max {
+        return vec![value.to_string()];
+    }
+
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    while value.len() - start > last_max {
+        let mut end = (start + max_content).min(value.len());
+        while end > start && !value.is_char_boundary(end) {
+            end -= 1;
+        }
+        if end == start {
+            // The budget is smaller than a single multi-byte character; include it
+            // whole rather than loop forever. The resulting card will just run long.
+            end = start + value[start..].chars().next().map_or(1, char::len_utf8);
+        }
+        chunks.push(value[start..end].to_string());
+        start = end;
+    }
+    chunks.push(value[start..].to_string());
+
+    chunks
+}
+
+/// Renders a string-valued keyword as one or more fixed 80-byte cards. Values that fit
+/// in a single card's value field are written as before; longer ones are split across
+/// `CONTINUE` cards, each fragment but the last ending in `&` to mark that more of the
+/// string follows.
+fn format_string_card(name_field: &str, value: &str, comment: Option<&str>) -> String {
+    let chunks = split_string_value(value, name_field.len(), comment.is_some());
+    let mut cards = String::new();
+
+    for (i, chunk) in chunks.iter().enumerate() {
+        let is_last = i == chunks.len() - 1;
+
+        let quoted = if is_last {
+            format!("'{}'", pad_right_bytes(chunk, 8))
+        } else {
+            format!("'{}&'", chunk)
+        };
+
+        let mut card = if i == 0 {
+            format!("{}= {}", name_field, pad_right_bytes(&quoted, 20))
+        } else {
+            format!(
+                "{}  {}",
+                pad_right_bytes("CONTINUE", 8),
+                pad_right_bytes(&quoted, 20)
+            )
+        };
+
+        if is_last {
+            if let Some(comment) = comment {
+                push_comment(&mut card, comment);
+            }
+        }
+
+        truncate_to_bytes(&mut card, 80);
+        cards.push_str(&pad_right_bytes(&card, 80));
+    }
+
+    cards
+}
+
+/// Renders a single keyword as one or more fixed 80-byte FITS cards: the name
+/// left-justified in columns 1-8 (or, for `HIERARCH`-style long names, prefixed
+/// accordingly), the `= ` value indicator in columns 9-10, a 20-byte value field
+/// (left-justified for strings, right-justified otherwise), and the comment after
+/// ` / `. String values too long for one card are split across `CONTINUE` cards rather
+/// than truncated.
+fn format_card(keyword: &Keyword) -> String {
+    let name_field = if keyword.name.len() <= 8 {
+        pad_right_bytes(&keyword.name, 8)
+    } else {
+        format!("HIERARCH {}", keyword.name)
+    };
+
+    if let FITSValue::String(s) = &keyword.value {
+        return format_string_card(&name_field, s, keyword.comment.as_deref());
+    }
+
+    let value = format_card_value(&keyword.value);
+    let value_field = pad_left_bytes(&value, 20);
+
+    let mut card = format!("{}= {}", name_field, value_field);
+
+    if let Some(comment) = &keyword.comment {
+        push_comment(&mut card, comment);
+    }
+
+    truncate_to_bytes(&mut card, 80);
+
+    pad_right_bytes(&card, 80)
+}
+
+impl Header {
+    /// Serializes this header as fixed-width FITS cards, appends an `END` card, and
+    /// pads the result with spaces to a multiple of 2880 bytes.
+    pub fn to_fits_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity((self.keywords.len() + 1) * 80);
+
+        for keyword in &self.keywords {
+            bytes.extend_from_slice(format_card(keyword).as_bytes());
+        }
+
+        bytes.extend_from_slice(format!("{:<80}", "END").as_bytes());
+
+        let padding = (2880 - bytes.len() % 2880) % 2880;
+        bytes.resize(bytes.len() + padding, b' ');
+
+        bytes
+    }
+}
+
+/// Writes `header`'s FITS-compliant byte representation to `writer`.
+pub fn write_header<W: Write>(header: &Header, writer: &mut W) -> std::io::Result<()> {
+    writer.write_all(&header.to_fits_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip(header: &Header) -> Header {
+        Header::parse(&header.to_fits_bytes()).expect("serialized header should re-parse")
+    }
+
+    #[test]
+    fn roundtrips_a_hierarch_keyword() {
+        let mut header = Header::new();
+        header.add_keyword(Keyword::new(
+            "ESO DET CHIP1 GAIN".to_string(),
+            FITSValue::Float(2.1),
+            None,
+        ));
+
+        let parsed = roundtrip(&header);
+        let keyword = parsed
+            .get_keyword("ESO DET CHIP1 GAIN")
+            .expect("HIERARCH keyword should round-trip under its full name");
+
+        assert!(matches!(keyword.value, FITSValue::Float(v) if v == 2.1));
+    }
+
+    #[test]
+    fn roundtrips_a_long_string_requiring_continue_cards() {
+        let long_value = "x".repeat(200);
+
+        let mut header = Header::new();
+        header.add_keyword(Keyword::new(
+            "LONGSTR".to_string(),
+            FITSValue::String(long_value.clone()),
+            None,
+        ));
+
+        let parsed = roundtrip(&header);
+        let keyword = parsed.get_keyword("LONGSTR").unwrap();
+
+        assert!(matches!(&keyword.value, FITSValue::String(s) if *s == long_value));
+    }
+
+    #[test]
+    fn roundtrips_a_long_hierarch_string_with_a_comment() {
+        let long_value = "y".repeat(200);
+
+        let mut header = Header::new();
+        header.add_keyword(Keyword::new(
+            "ESO INS MODE".to_string(),
+            FITSValue::String(long_value.clone()),
+            Some("instrument mode".to_string()),
+        ));
+
+        let parsed = roundtrip(&header);
+        let keyword = parsed.get_keyword("ESO INS MODE").unwrap();
+
+        assert!(matches!(&keyword.value, FITSValue::String(s) if *s == long_value));
+        assert_eq!(keyword.comment.as_deref(), Some("instrument mode"));
+    }
+
+    #[test]
+    fn roundtrips_a_string_with_a_comment() {
+        let value = "hello".to_string();
+
+        let mut header = Header::new();
+        header.add_keyword(Keyword::new(
+            "GREET".to_string(),
+            FITSValue::String(value.clone()),
+            Some("a greeting".to_string()),
+        ));
+
+        let parsed = roundtrip(&header);
+        let keyword = parsed.get_keyword("GREET").unwrap();
+
+        assert!(matches!(&keyword.value, FITSValue::String(s) if *s == value));
+        assert_eq!(keyword.comment.as_deref(), Some("a greeting"));
+    }
+
+    #[test]
+    fn does_not_panic_on_a_long_multibyte_hierarch_string() {
+        let long_value = "é".repeat(200);
+
+        let mut header = Header::new();
+        header.add_keyword(Keyword::new(
+            "ESO DET MULTIBYTE".to_string(),
+            FITSValue::String(long_value.clone()),
+            None,
+        ));
+
+        let parsed = roundtrip(&header);
+        let keyword = parsed.get_keyword("ESO DET MULTIBYTE").unwrap();
+
+        assert!(matches!(&keyword.value, FITSValue::String(s) if *s == long_value));
+    }
+
+    fn simple_header_bytes() -> Vec<u8> {
+        let mut header = Header::new();
+        header.add_keyword(Keyword::new(
+            "SIMPLE".to_string(),
+            FITSValue::Bool(true),
+            Some("conforms to FITS".to_string()),
+        ));
+        header.to_fits_bytes()
+    }
+
+    #[test]
+    fn header_parse_reads_an_in_memory_buffer() {
+        let bytes = simple_header_bytes();
+
+        let header = Header::parse(&bytes).expect("Header::parse should read its own bytes");
+        let keyword = header.get_keyword("SIMPLE").unwrap();
+
+        assert!(matches!(keyword.value, FITSValue::Bool(true)));
+    }
+
+    #[test]
+    fn gzip_mode_auto_detects_and_decompresses_a_gzip_stream() {
+        let bytes = simple_header_bytes();
+
+        let mut gz_bytes = Vec::new();
+        {
+            let mut encoder = flate2::write::GzEncoder::new(&mut gz_bytes, flate2::Compression::default());
+            encoder.write_all(&bytes).unwrap();
+            encoder.finish().unwrap();
+        }
+
+        let header = read_header_from_reader(gz_bytes.as_slice(), GzipMode::Auto)
+            .expect("GzipMode::Auto should detect and decompress a gzip stream");
+
+        assert!(header.get_keyword("SIMPLE").is_some());
+    }
+
+    #[test]
+    fn gzip_mode_never_reads_an_uncompressed_stream_as_is() {
+        let bytes = simple_header_bytes();
+
+        let header = read_header_from_reader(bytes.as_slice(), GzipMode::Never)
+            .expect("GzipMode::Never should read an already-uncompressed stream");
+
+        assert!(header.get_keyword("SIMPLE").is_some());
+    }
+
+    #[test]
+    fn gzip_mode_never_fails_on_a_gzip_stream_it_was_told_not_to_decompress() {
+        let bytes = simple_header_bytes();
+
+        let mut gz_bytes = Vec::new();
+        {
+            let mut encoder = flate2::write::GzEncoder::new(&mut gz_bytes, flate2::Compression::default());
+            encoder.write_all(&bytes).unwrap();
+            encoder.finish().unwrap();
+        }
+
+        // Asked not to decompress, the raw gzip bytes don't parse as FITS cards at all,
+        // so this should surface as an error rather than silently misreading the header.
+        assert!(read_header_from_reader(gz_bytes.as_slice(), GzipMode::Never).is_err());
+    }
+
+    #[test]
+    fn extname_reads_the_extname_keyword() {
+        let mut header = Header::new();
+        header.add_keyword(Keyword::new(
+            "EXTNAME".to_string(),
+            FITSValue::String("SCI".to_string()),
+            None,
+        ));
+
+        assert_eq!(header.extname().as_deref(), Some("SCI"));
+    }
+
+    #[test]
+    fn extname_is_none_without_the_keyword() {
+        let header = Header::new();
+        assert_eq!(header.extname(), None);
+    }
+
+    #[test]
+    fn read_hdus_parses_every_extension_and_skips_its_data_unit() {
+        let mut primary = Header::new();
+        primary.add_keyword(Keyword::new("SIMPLE".to_string(), FITSValue::Bool(true), None));
+        primary.add_keyword(Keyword::new("BITPIX".to_string(), FITSValue::Integer(8), None));
+        primary.add_keyword(Keyword::new("NAXIS".to_string(), FITSValue::Integer(0), None));
+
+        let mut extension = Header::new();
+        extension.add_keyword(Keyword::new(
+            "XTENSION".to_string(),
+            FITSValue::String("IMAGE".to_string()),
+            None,
+        ));
+        extension.add_keyword(Keyword::new(
+            "EXTNAME".to_string(),
+            FITSValue::String("SCI".to_string()),
+            None,
+        ));
+        extension.add_keyword(Keyword::new("BITPIX".to_string(), FITSValue::Integer(8), None));
+        extension.add_keyword(Keyword::new("NAXIS".to_string(), FITSValue::Integer(1), None));
+        extension.add_keyword(Keyword::new("NAXIS1".to_string(), FITSValue::Integer(10), None));
+
+        let mut bytes = primary.to_fits_bytes();
+        bytes.extend(extension.to_fits_bytes());
+        // The extension's 10-byte data unit, rounded up to one 2880-byte block.
+        bytes.resize(bytes.len() + 2880, 0);
+
+        let path = std::env::temp_dir().join(format!(
+            "rheader-read-hdus-test-{}.fits",
+            std::process::id()
+        ));
+        std::fs::write(&path, &bytes).unwrap();
+
+        let hdus = read_hdus(&path);
+        std::fs::remove_file(&path).ok();
+
+        let hdus = hdus.expect("read_hdus should parse both the primary header and the extension");
+        assert_eq!(hdus.len(), 2);
+        assert_eq!(hdus[0].extname(), None);
+        assert_eq!(hdus[1].extname().as_deref(), Some("SCI"));
+    }
+
+    /// Builds a single 80-byte card from its content, space-padded.
+    fn card(s: &str) -> Vec<u8> {
+        let mut bytes = s.as_bytes().to_vec();
+        assert!(bytes.len() <= 80, "card content longer than 80 bytes: {}", s);
+        bytes.resize(80, b' ');
+        bytes
+    }
+
+    #[test]
+    fn parse_header_cards_stitches_a_hand_written_continue_card() {
+        let mut buf = Vec::new();
+        buf.extend(card("LONGSTR = 'first part&'"));
+        buf.extend(card("CONTINUE  'second part'"));
+
+        let header = parse_header_cards(&buf);
+        let keyword = header.get_keyword("LONGSTR").unwrap();
+
+        assert!(matches!(&keyword.value, FITSValue::String(s) if s == "first partsecond part"));
+    }
+
+    #[test]
+    fn parse_header_cards_stitches_a_hand_written_hierarch_continue_card() {
+        let mut buf = Vec::new();
+        buf.extend(card("HIERARCH ESO DET GAIN = 'first part&'"));
+        buf.extend(card("CONTINUE  'second part'"));
+
+        let header = parse_header_cards(&buf);
+        let keyword = header.get_keyword("ESO DET GAIN").unwrap();
+
+        assert!(matches!(&keyword.value, FITSValue::String(s) if s == "first partsecond part"));
+    }
+
+    #[test]
+    fn parse_header_cards_reads_a_hierarch_keyword_and_comment() {
+        let mut buf = Vec::new();
+        buf.extend(card("HIERARCH ESO DET CHIP1 GAIN = 2.1 / electrons/ADU"));
+
+        let header = parse_header_cards(&buf);
+        let keyword = header.get_keyword("ESO DET CHIP1 GAIN").unwrap();
+
+        assert!(matches!(keyword.value, FITSValue::Float(v) if v == 2.1));
+        assert_eq!(keyword.comment.as_deref(), Some("electrons/ADU"));
+    }
+
+    #[test]
+    fn parse_header_cards_marks_an_unparseable_value_as_invalid() {
+        let mut buf = Vec::new();
+        buf.extend(card("BADKEY  = not_a_value"));
+
+        let header = parse_header_cards(&buf);
+        let keyword = header.get_keyword("BADKEY").unwrap();
+
+        assert!(!keyword.is_valid());
+        assert!(matches!(keyword.value, FITSValue::Invalid));
+    }
+
+    #[test]
+    fn errors_with_unexpected_eof_on_empty_input() {
+        let reader = std::io::Cursor::new(Vec::<u8>::new());
+
+        let err =
+            read_header_from_reader_with_limit(reader, GzipMode::Never, DEFAULT_MAX_HEADER_BLOCKS)
+                .unwrap_err();
+
+        assert!(matches!(
+            err.downcast_ref::<HeaderError>(),
+            Some(HeaderError::UnexpectedEof)
+        ));
+    }
+
+    #[test]
+    fn errors_with_truncated_block_on_a_short_stream() {
+        let reader = std::io::Cursor::new(vec![b' '; 100]);
+
+        let err =
+            read_header_from_reader_with_limit(reader, GzipMode::Never, DEFAULT_MAX_HEADER_BLOCKS)
+                .unwrap_err();
+
+        match err.downcast_ref::<HeaderError>() {
+            Some(HeaderError::TruncatedBlock { expected, actual }) => {
+                assert_eq!(*expected, 2880);
+                assert_eq!(*actual, 100);
+            }
+            other => panic!("expected TruncatedBlock, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn errors_with_too_many_blocks_when_no_end_keyword_is_found() {
+        let reader = std::io::Cursor::new(vec![b' '; 2880 * 3]);
+
+        let err = read_header_from_reader_with_limit(reader, GzipMode::Never, 1).unwrap_err();
+
+        assert!(matches!(
+            err.downcast_ref::<HeaderError>(),
+            Some(HeaderError::TooManyBlocks(1))
+        ));
+    }
+
+    #[test]
+    fn errors_with_malformed_card_when_end_is_not_card_aligned() {
+        let mut buf = vec![b' '; 2880];
+        buf[81..84].copy_from_slice(b"END");
+        let reader = std::io::Cursor::new(buf);
+
+        let err =
+            read_header_from_reader_with_limit(reader, GzipMode::Never, DEFAULT_MAX_HEADER_BLOCKS)
+                .unwrap_err();
+
+        assert!(matches!(
+            err.downcast_ref::<HeaderError>(),
+            Some(HeaderError::MalformedCard(_))
+        ));
+    }
 }
@@ -10,5 +10,8 @@ mod python;
 pub mod tools;
 
 // Re-exporting modules
-pub use crate::header::{FITSValue, Header, Keyword, read_header};
+pub use crate::header::{
+    DEFAULT_MAX_HEADER_BLOCKS, FITSValue, GzipMode, Header, HeaderError, Keyword, read_hdus,
+    read_header, read_header_from_reader, read_header_from_reader_with_limit, write_header,
+};
 pub use crate::tools::is_gzip_file;
@@ -5,12 +5,50 @@
  *  @License: BSD 3-clause (http://www.opensource.org/licenses/BSD-3-Clause)
  */
 
-use pyo3::{IntoPyObjectExt, prelude::*};
+use pyo3::{IntoPyObjectExt, create_exception, prelude::*};
 
-use pyo3::exceptions::PyIOError;
+use pyo3::exceptions::{PyException, PyValueError};
 use pyo3::types::{PyDict, PyString};
 
-use crate::header::read_header;
+use crate::header::{
+    FITSValue, GzipMode, Header as RHeader, HeaderError, Keyword as RKeyword, read_hdus,
+    read_header, read_header_from_reader,
+};
+
+create_exception!(
+    _rheader,
+    FitsError,
+    PyException,
+    "Base class for all rheader errors."
+);
+create_exception!(
+    _rheader,
+    FitsReadError,
+    FitsError,
+    "Raised when a FITS file or stream could not be read (I/O failure)."
+);
+create_exception!(
+    _rheader,
+    FitsParseError,
+    FitsError,
+    "Raised when a FITS header is malformed, e.g. missing END keyword or a truncated block."
+);
+create_exception!(
+    _rheader,
+    FitsValueError,
+    FitsError,
+    "Raised when a keyword's value did not match any recognized FITS value type."
+);
+
+// Maps an `anyhow::Error` coming out of the header module onto the rheader exception
+// hierarchy, distinguishing I/O failures from malformed headers.
+fn map_header_error(error: anyhow::Error) -> PyErr {
+    match error.downcast_ref::<HeaderError>() {
+        Some(HeaderError::Io(_)) => FitsReadError::new_err(error.to_string()),
+        Some(_) => FitsParseError::new_err(error.to_string()),
+        None => FitsReadError::new_err(error.to_string()),
+    }
+}
 
 // Python class wrapper for Header.
 #[pyclass]
@@ -69,11 +107,19 @@ impl Keyword {
     }
 }
 
-// Read header to a Python dictionary.
-#[pyfunction]
-#[pyo3(name = "read_header", signature = (path))]
-fn _read_header(py: Python<'_>, path: &str) -> PyResult<Py<PyDict>> {
-    let header = read_header(path).map_err(|e| PyIOError::new_err(format!("{}", e)))?;
+// Converts a parsed `Header` into a Python dictionary of (value, comment) tuples. If
+// `strict`, raises `FitsValueError` on the first keyword whose value failed to parse,
+// surfacing information that `Keyword::is_valid` otherwise only tracks internally;
+// otherwise such values are reported as `None`, same as before this validation existed.
+fn header_to_dict(py: Python<'_>, header: RHeader, strict: bool) -> PyResult<Py<PyDict>> {
+    if strict {
+        if let Some(invalid) = header.keywords.iter().find(|k| !k.is_valid()) {
+            return Err(FitsValueError::new_err(format!(
+                "keyword '{}' has a value that did not match any recognized FITS type",
+                invalid.name
+            )));
+        }
+    }
 
     let dict = PyDict::new(py);
 
@@ -106,7 +152,91 @@ fn _read_header(py: Python<'_>, path: &str) -> PyResult<Py<PyDict>> {
         };
     }
 
-    return Ok(dict.into());
+    Ok(dict.into())
+}
+
+// Read header to a Python dictionary.
+#[pyfunction]
+#[pyo3(name = "read_header", signature = (path))]
+fn _read_header(py: Python<'_>, path: &str) -> PyResult<Py<PyDict>> {
+    let header = read_header(path).map_err(map_header_error)?;
+
+    header_to_dict(py, header, true)
+}
+
+// Parses the `gzip` argument shared by the bytes-based Python entry points into a
+// `GzipMode`, so Python callers can pass plain strings instead of needing a bound enum.
+fn parse_gzip_mode(gzip: &str) -> PyResult<GzipMode> {
+    match gzip.to_ascii_lowercase().as_str() {
+        "auto" => Ok(GzipMode::Auto),
+        "always" => Ok(GzipMode::Always),
+        "never" => Ok(GzipMode::Never),
+        other => Err(PyValueError::new_err(format!(
+            "invalid gzip mode '{}': expected 'auto', 'always', or 'never'",
+            other
+        ))),
+    }
+}
+
+// Read header from an in-memory bytes buffer, e.g. pulled from S3 or HTTP. `gzip`
+// defaults to "auto" so a caller that isn't sure whether the object is gzip-compressed
+// (e.g. a `.fits.gz` fetched from S3) doesn't have to decompress it themselves first.
+#[pyfunction]
+#[pyo3(name = "read_header_from_bytes", signature = (data, gzip = "auto"))]
+fn _read_header_from_bytes(py: Python<'_>, data: &[u8], gzip: &str) -> PyResult<Py<PyDict>> {
+    let mode = parse_gzip_mode(gzip)?;
+    let header = read_header_from_reader(data, mode).map_err(map_header_error)?;
+
+    header_to_dict(py, header, true)
+}
+
+// Read every HDU in a multi-extension FITS file to a list of Python dictionaries. Value
+// validation is non-strict here: real survey mosaics can have a stray unparseable
+// keyword in one extension, and that shouldn't cost the caller every other HDU.
+#[pyfunction]
+#[pyo3(name = "read_hdus", signature = (path))]
+fn _read_hdus(py: Python<'_>, path: &str) -> PyResult<Vec<Py<PyDict>>> {
+    let headers = read_hdus(path).map_err(map_header_error)?;
+
+    headers
+        .into_iter()
+        .map(|h| header_to_dict(py, h, false))
+        .collect()
+}
+
+// Converts a Python value from a `read_header`-style dict back into a `FITSValue`.
+fn py_to_fits_value(value: &Bound<'_, PyAny>) -> PyResult<FITSValue> {
+    if value.is_none() {
+        Ok(FITSValue::Null)
+    } else if let Ok(b) = value.extract::<bool>() {
+        Ok(FITSValue::Bool(b))
+    } else if let Ok(i) = value.extract::<i64>() {
+        Ok(FITSValue::Integer(i))
+    } else if let Ok(f) = value.extract::<f64>() {
+        Ok(FITSValue::Float(f))
+    } else if let Ok(s) = value.extract::<String>() {
+        Ok(FITSValue::String(s))
+    } else {
+        Ok(FITSValue::Invalid)
+    }
+}
+
+// Writes a `read_header`-style dict of (value, comment) tuples as FITS header bytes, so
+// a header can be read, mutated, and written back out without touching the filesystem.
+#[pyfunction]
+#[pyo3(name = "write_header", signature = (header))]
+fn _write_header(header: &Bound<'_, PyDict>) -> PyResult<Vec<u8>> {
+    let mut rust_header = RHeader::new();
+
+    for (name, item) in header.iter() {
+        let name = name.extract::<String>()?;
+        let value = py_to_fits_value(&item.get_item(0)?)?;
+        let comment = item.get_item(1)?.extract::<Option<String>>()?;
+
+        rust_header.add_keyword(RKeyword::new(name, value, comment));
+    }
+
+    Ok(rust_header.to_fits_bytes())
 }
 
 // Read header and convert to Header class.
@@ -143,8 +273,100 @@ fn _read_header_to_class(py: Python<'_>, path: &str) -> PyResult<Header> {
 #[pymodule(name = "_rheader")]
 fn rheader_python_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(_read_header, m)?)?;
+    m.add_function(wrap_pyfunction!(_read_header_from_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(_read_hdus, m)?)?;
+    m.add_function(wrap_pyfunction!(_write_header, m)?)?;
     m.add_function(wrap_pyfunction!(_read_header_to_class, m)?)?;
     m.add_class::<Header>()?;
     m.add_class::<Keyword>()?;
+    m.add("FitsError", m.py().get_type::<FitsError>())?;
+    m.add("FitsReadError", m.py().get_type::<FitsReadError>())?;
+    m.add("FitsParseError", m.py().get_type::<FitsParseError>())?;
+    m.add("FitsValueError", m.py().get_type::<FitsValueError>())?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_known_gzip_modes() {
+        assert_eq!(parse_gzip_mode("auto").unwrap(), GzipMode::Auto);
+        assert_eq!(parse_gzip_mode("Always").unwrap(), GzipMode::Always);
+        assert_eq!(parse_gzip_mode("NEVER").unwrap(), GzipMode::Never);
+    }
+
+    #[test]
+    fn rejects_an_unknown_gzip_mode() {
+        assert!(parse_gzip_mode("sometimes").is_err());
+    }
+
+    #[test]
+    fn maps_io_errors_to_fits_read_error() {
+        let io_err = std::io::Error::new(std::io::ErrorKind::NotFound, "no such file");
+        let err = map_header_error(anyhow::Error::new(HeaderError::Io(io_err)));
+
+        Python::attach(|py| {
+            assert!(err.is_instance_of::<FitsReadError>(py));
+        });
+    }
+
+    #[test]
+    fn maps_malformed_card_errors_to_fits_parse_error() {
+        let err = map_header_error(anyhow::Error::new(HeaderError::MalformedCard(
+            "bad card".to_string(),
+        )));
+
+        Python::attach(|py| {
+            assert!(err.is_instance_of::<FitsParseError>(py));
+        });
+    }
+
+    #[test]
+    fn maps_unrecognized_errors_to_fits_read_error() {
+        let err = map_header_error(anyhow::anyhow!("some other failure"));
+
+        Python::attach(|py| {
+            assert!(err.is_instance_of::<FitsReadError>(py));
+        });
+    }
+
+    // Builds a header with one keyword whose value doesn't match any FITSValue variant,
+    // the way `Keyword::is_valid` tracks internally, by parsing a hand-written card
+    // rather than going through `RKeyword::new` (which always marks a keyword valid).
+    fn header_with_invalid_keyword() -> RHeader {
+        let mut buf = Vec::new();
+
+        let mut card = b"BADKEY  = not_a_value".to_vec();
+        card.resize(80, b' ');
+        buf.extend(card);
+
+        let mut end = b"END".to_vec();
+        end.resize(80, b' ');
+        buf.extend(end);
+
+        buf.resize(2880, b' ');
+
+        RHeader::parse(&buf).expect("header with an unparseable value should still parse")
+    }
+
+    #[test]
+    fn header_to_dict_raises_fits_value_error_for_an_invalid_keyword_in_strict_mode() {
+        let header = header_with_invalid_keyword();
+
+        Python::attach(|py| {
+            let err = header_to_dict(py, header, true).unwrap_err();
+            assert!(err.is_instance_of::<FitsValueError>(py));
+        });
+    }
+
+    #[test]
+    fn header_to_dict_reports_an_invalid_keyword_as_none_when_not_strict() {
+        let header = header_with_invalid_keyword();
+
+        Python::attach(|py| {
+            assert!(header_to_dict(py, header, false).is_ok());
+        });
+    }
+}